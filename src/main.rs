@@ -2,19 +2,25 @@ use anyhow::anyhow;
 use anyhow::Error;
 use anyhow::Result;
 use chrono::Datelike;
+use chrono::Duration;
 use chrono::NaiveDate;
 use chrono::Weekday;
 use clap::Parser;
 use csv::ReaderBuilder;
+use icalendar::Alarm;
 use icalendar::Calendar;
 use icalendar::Component;
 use icalendar::Event;
 use icalendar::EventLike;
+use icalendar::Property;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use std::fs::File;
 use std::hash::Hash;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 
 fn default_output_path() -> PathBuf {
@@ -30,9 +36,121 @@ struct Args {
     #[arg(short, long)]
     calendar_path: PathBuf,
 
+    /// Collapse same-type pickups into RRULE recurrences (with EXDATE/RDATE exceptions)
+    /// instead of emitting one VEVENT per pickup date
+    #[arg(long)]
+    recurring: bool,
+
+    /// Attach a VALARM this long before each pickup (e.g. `18h`, `1d`); repeatable
+    #[arg(long = "reminder")]
+    reminders: Vec<String>,
+
+    /// Output format. Defaults to the output path's extension (falling back to .ics)
+    #[arg(long, value_enum)]
+    format: Option<OutputFormat>,
+
     // Path to the output calendar file
     #[arg(default_value=default_output_path().into_os_string())]
     output_path: PathBuf,
+
+    /// Print an agenda of upcoming pickups instead of writing a calendar file
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum OutputFormat {
+    Ics,
+    Html,
+    Md,
+}
+
+fn infer_format(path: &Path) -> OutputFormat {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("html") | Some("htm") => OutputFormat::Html,
+        Some("md") => OutputFormat::Md,
+        _ => OutputFormat::Ics,
+    }
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// Print the next pickups within a date window, instead of writing an output file
+    Agenda {
+        /// Start of the window (YYYY-MM-DD). Defaults to today
+        #[arg(long)]
+        from: Option<NaiveDate>,
+
+        /// End of the window (YYYY-MM-DD). Defaults to the end of the month `--from` falls in,
+        /// or, when `--from` is omitted too, to the end of the `--period` window
+        #[arg(long)]
+        to: Option<NaiveDate>,
+
+        /// Shorthand window relative to today, used when `--from`/`--to` are omitted
+        #[arg(long, value_enum)]
+        period: Option<AgendaPeriod>,
+    },
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum AgendaPeriod {
+    Day,
+    Week,
+    Month,
+}
+
+fn end_of_month(date: NaiveDate) -> NaiveDate {
+    let (next_year, next_month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .expect("valid month")
+        .pred_opt()
+        .expect("valid predecessor")
+}
+
+fn resolve_agenda_window(
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    period: Option<AgendaPeriod>,
+    today: NaiveDate,
+) -> (NaiveDate, NaiveDate) {
+    let (from, to) = match (from, to) {
+        (Some(from), Some(to)) => (from, to),
+        (Some(from), None) => (from, end_of_month(from)),
+        (None, Some(to)) => (today, to),
+        (None, None) => match period {
+            Some(AgendaPeriod::Day) => (today, today),
+            Some(AgendaPeriod::Week) => (today, today + Duration::days(6)),
+            Some(AgendaPeriod::Month) | None => (today, end_of_month(today)),
+        },
+    };
+    // A user-supplied --from/--to can come in reversed; swap rather than handing
+    // BTreeMap::range a backwards bound, which panics.
+    if from > to {
+        (to, from)
+    } else {
+        (from, to)
+    }
+}
+
+fn print_agenda(
+    schedule: &HashMap<TrashType, Vec<NaiveDate>>,
+    names: &HashMap<TrashType, String>,
+    from: NaiveDate,
+    to: NaiveDate,
+) {
+    let by_date = invert_schedule(schedule, names);
+    for (date, pickups) in by_date.range(from..=to) {
+        let types = pickups
+            .iter()
+            .map(|(_, name)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("{} ({}): {}", date.format("%Y-%m-%d"), date.format("%A"), types);
+    }
 }
 
 fn main() -> Result<()> {
@@ -49,23 +167,52 @@ fn main() -> Result<()> {
     let year = parse_year(&mut csv_iter)?;
     let names = parse_trash_names(&mut csv_iter)?;
     let entries = parse_trash_entries(&mut csv_iter)?;
-    let conversions = parse_conversions(&mut csv_iter)?;
+    let conversion_tables = parse_conversions(&mut csv_iter, &names)?;
+    let reminders = args
+        .reminders
+        .iter()
+        .map(|raw| parse_reminder_duration(raw))
+        .collect::<Result<Vec<_>>>()?;
+
+    let schedule = build_schedule(
+        entries,
+        conversion_tables.remaps,
+        conversion_tables.exceptions,
+        year,
+    );
+
+    if let Some(Command::Agenda { from, to, period }) = args.command {
+        let today = chrono::Local::now().date_naive();
+        let (window_from, window_to) = resolve_agenda_window(from, to, period, today);
+        print_agenda(&schedule, &names, window_from, window_to);
+        return Ok(());
+    }
+
+    let format = args.format.unwrap_or_else(|| infer_format(&args.output_path));
 
-    let calendar = fill_calendar(entries, conversions, year, names)?;
+    let output = match format {
+        OutputFormat::Ics => {
+            let calendar = fill_calendar(schedule, &names, year, args.recurring, &reminders)?;
+            format!("{}", calendar)
+        }
+        OutputFormat::Html => render_html_calendar(&schedule, &names, year),
+        OutputFormat::Md => render_markdown_calendar(&schedule, &names, year),
+    };
 
     let mut file = File::create(args.output_path)?;
-    file.write_all(format!("{}", calendar).as_bytes())?;
+    file.write_all(output.as_bytes())?;
     file.flush()?;
     Ok(())
 }
 
-fn fill_calendar(
+fn build_schedule(
     entries: Vec<InputTrashEntry>,
     conversions: HashMap<SimpleDate, SimpleDate>,
+    exceptions: HashMap<SimpleDate, ConversionException>,
     year: i32,
-    names: HashMap<TrashType, String>,
-) -> Result<Calendar> {
-    let mut calendar = Calendar::new();
+) -> HashMap<TrashType, Vec<NaiveDate>> {
+    let mut dates_by_type: HashMap<TrashType, Vec<NaiveDate>> = HashMap::new();
+
     for entry in entries {
         let mut process_date = |date: NaiveDate| {
             let simple_date = SimpleDate {
@@ -75,12 +222,14 @@ fn fill_calendar(
             let converted_date = conversions.get(&simple_date).unwrap_or(&simple_date);
             let date = NaiveDate::from_ymd_opt(year, converted_date.month, converted_date.day)
                 .expect("Shouldn't happen if your conversions are okay");
-            let event = Event::new()
-                .all_day(date)
-                .summary(names.get(&entry.ty).expect("No trash type name"))
-                .description(names.get(&entry.ty).expect("No trash type name"))
-                .done();
-            calendar.push(event);
+            let final_date = SimpleDate {
+                month: date.month(),
+                day: date.day(),
+            };
+            if matches!(exceptions.get(&final_date), Some(ConversionException::Removed)) {
+                return;
+            }
+            dates_by_type.entry(entry.ty).or_default().push(date);
         };
         match entry.day {
             InputTrashDate::Day(day) => process_date(
@@ -101,9 +250,368 @@ fn fill_calendar(
             }
         }
     }
+
+    for (simple_date, exception) in &exceptions {
+        if let ConversionException::Added(ty) = exception {
+            let date = NaiveDate::from_ymd_opt(year, simple_date.month, simple_date.day)
+                .expect("Shouldn't happen if your conversions are okay");
+            dates_by_type.entry(*ty).or_default().push(date);
+        }
+    }
+
+    dates_by_type
+}
+
+fn fill_calendar(
+    schedule: HashMap<TrashType, Vec<NaiveDate>>,
+    names: &HashMap<TrashType, String>,
+    year: i32,
+    recurring: bool,
+    reminders: &[Duration],
+) -> Result<Calendar> {
+    let mut calendar = Calendar::new();
+
+    for (ty, dates) in schedule {
+        let name = names.get(&ty).expect("No trash type name");
+        let events = if recurring {
+            collapse_to_recurrence(dates, year, name, reminders)
+        } else {
+            dates
+                .into_iter()
+                .map(|date| new_pickup_event(date, name, reminders))
+                .collect()
+        };
+        for event in events {
+            calendar.push(event);
+        }
+    }
+
     Ok(calendar.done())
 }
 
+fn invert_schedule(
+    schedule: &HashMap<TrashType, Vec<NaiveDate>>,
+    names: &HashMap<TrashType, String>,
+) -> BTreeMap<NaiveDate, Vec<(TrashType, String)>> {
+    let mut by_date: BTreeMap<NaiveDate, Vec<(TrashType, String)>> = BTreeMap::new();
+    for (ty, dates) in schedule {
+        let name = names.get(ty).expect("No trash type name");
+        for date in dates {
+            by_date.entry(*date).or_default().push((*ty, name.clone()));
+        }
+    }
+    for entries in by_date.values_mut() {
+        entries.sort_by(|a, b| a.1.cmp(&b.1));
+    }
+    by_date
+}
+
+fn escape_html(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn trash_type_color(ty: TrashType) -> &'static str {
+    match ty {
+        TrashType::Mixed => "#555555",
+        TrashType::Metal => "#8c8c8c",
+        TrashType::Paper => "#2b6cb0",
+        TrashType::Glass => "#2f855a",
+        TrashType::Bio => "#6b4226",
+        TrashType::Big => "#b83280",
+        TrashType::ChristmasTree => "#276749",
+    }
+}
+
+fn render_html_calendar(
+    schedule: &HashMap<TrashType, Vec<NaiveDate>>,
+    names: &HashMap<TrashType, String>,
+    year: i32,
+) -> String {
+    let by_date = invert_schedule(schedule, names);
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!("<title>Trash pickups {}</title>\n", year));
+    html.push_str(
+        "<style>\ntable{border-collapse:collapse;width:100%;margin-bottom:2em}\n\
+th,td{border:1px solid #ccc;vertical-align:top;padding:4px;width:14.28%;height:4em}\n\
+th{background:#eee}\n\
+.pickup{display:block;border-radius:3px;padding:1px 4px;margin-top:2px;color:#fff;font-size:0.85em}\n\
+</style>\n",
+    );
+    html.push_str("</head>\n<body>\n");
+    html.push_str(&format!("<h1>Trash pickups {}</h1>\n", year));
+
+    for month in 1..=12u32 {
+        let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).expect("valid month");
+        html.push_str(&format!("<h2>{}</h2>\n<table>\n<tr>", first_of_month.format("%B")));
+        for weekday_label in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"] {
+            html.push_str(&format!("<th>{}</th>", weekday_label));
+        }
+        html.push_str("</tr>\n<tr>");
+
+        let lead_blanks = first_of_month.weekday().num_days_from_monday();
+        for _ in 0..lead_blanks {
+            html.push_str("<td></td>");
+        }
+
+        let mut column = lead_blanks;
+        let mut day = first_of_month;
+        loop {
+            if column == 7 {
+                html.push_str("</tr>\n<tr>");
+                column = 0;
+            }
+            html.push_str("<td>");
+            html.push_str(&day.day().to_string());
+            if let Some(pickups) = by_date.get(&day) {
+                for (ty, name) in pickups {
+                    html.push_str(&format!(
+                        "<span class=\"pickup\" style=\"background:{}\">{}</span>",
+                        trash_type_color(*ty),
+                        escape_html(name)
+                    ));
+                }
+            }
+            html.push_str("</td>");
+            column += 1;
+
+            match day.succ_opt() {
+                Some(next) if next.month() == month => day = next,
+                _ => break,
+            }
+        }
+        while column < 7 {
+            html.push_str("<td></td>");
+            column += 1;
+        }
+        html.push_str("</tr>\n</table>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn render_markdown_calendar(
+    schedule: &HashMap<TrashType, Vec<NaiveDate>>,
+    names: &HashMap<TrashType, String>,
+    year: i32,
+) -> String {
+    let by_date = invert_schedule(schedule, names);
+    let mut out = String::new();
+    out.push_str(&format!("# Trash pickups {}\n", year));
+
+    let mut current_month = 0;
+    for (date, pickups) in &by_date {
+        if date.month() != current_month {
+            current_month = date.month();
+            out.push_str(&format!("\n## {}\n\n", date.format("%B")));
+        }
+        let types = pickups
+            .iter()
+            .map(|(_, name)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        out.push_str(&format!("- {} ({}): {}\n", date.format("%d %B"), date.format("%a"), types));
+    }
+    out
+}
+
+fn format_ics_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn parse_reminder_duration(raw: &str) -> Result<Duration> {
+    let raw = raw.trim();
+    let mut total = Duration::zero();
+    let mut digits = String::new();
+    let mut parsed_any = false;
+    for ch in raw.chars() {
+        if ch.is_ascii_digit() {
+            digits.push(ch);
+            continue;
+        }
+        if digits.is_empty() {
+            return Err(anyhow!(format!("Couldn't parse reminder duration {}", raw)));
+        }
+        let value: i64 = digits.parse()?;
+        digits.clear();
+        total += match ch {
+            'd' => Duration::days(value),
+            'h' => Duration::hours(value),
+            'm' => Duration::minutes(value),
+            's' => Duration::seconds(value),
+            _ => {
+                return Err(anyhow!(format!(
+                    "Unknown duration unit '{}' in reminder {}",
+                    ch, raw
+                )))
+            }
+        };
+        parsed_any = true;
+    }
+    if !digits.is_empty() || !parsed_any {
+        return Err(anyhow!(format!("Couldn't parse reminder duration {}", raw)));
+    }
+    Ok(total)
+}
+
+fn new_pickup_event(date: NaiveDate, name: &str, reminders: &[Duration]) -> Event {
+    let mut event = Event::new();
+    event.all_day(date);
+    event.summary(name);
+    event.description(name);
+    // VALARM triggers are relative to the event start, so a lead time becomes a negative offset.
+    for reminder in reminders {
+        event.alarm(Alarm::display(name, -*reminder));
+    }
+    event.done()
+}
+
+/// Infers a single weekly/bi-weekly RRULE that best fits `dates`, then reconciles it with
+/// EXDATE (rule dates that aren't real pickups) and RDATE (real pickups the rule misses),
+/// so that expanding RRULE+RDATE and subtracting EXDATE reproduces `dates` exactly.
+/// With fewer than two dates, or when the most common gap is not a whole number of weeks,
+/// no RRULE is emitted and each date becomes its own standalone event.
+fn collapse_to_recurrence(
+    mut dates: Vec<NaiveDate>,
+    year: i32,
+    name: &str,
+    reminders: &[Duration],
+) -> Vec<Event> {
+    dates.sort();
+    dates.dedup();
+
+    let standalone_events = |dates: Vec<NaiveDate>| -> Vec<Event> {
+        dates
+            .into_iter()
+            .map(|date| new_pickup_event(date, name, reminders))
+            .collect()
+    };
+
+    if dates.len() < 2 {
+        return standalone_events(dates);
+    }
+
+    // Modal gap between consecutive pickups, used to infer the weekly/bi-weekly cadence.
+    // Iterated from a BTreeMap (ascending by gap) and only replaced on a strictly higher
+    // count, so a tie is always broken deterministically in favor of the smaller gap.
+    let mut gap_counts: BTreeMap<i64, usize> = BTreeMap::new();
+    for pair in dates.windows(2) {
+        let gap = (pair[1] - pair[0]).num_days();
+        *gap_counts.entry(gap).or_insert(0) += 1;
+    }
+    let mut modal_gap_and_count: Option<(i64, usize)> = None;
+    for (gap, count) in &gap_counts {
+        let is_new_best = match modal_gap_and_count {
+            Some((_, best_count)) => *count > best_count,
+            None => true,
+        };
+        if is_new_best {
+            modal_gap_and_count = Some((*gap, *count));
+        }
+    }
+    let modal_gap = match modal_gap_and_count {
+        Some((gap, _)) if gap > 0 && gap % 7 == 0 => gap,
+        _ => return standalone_events(dates),
+    };
+    let interval = modal_gap / 7;
+
+    // Dominant weekday across all pickups, keyed by its Mon=0..Sun=6 index so ties are
+    // broken the same deterministic way as the modal gap above.
+    let mut weekday_counts: BTreeMap<u32, usize> = BTreeMap::new();
+    for date in &dates {
+        *weekday_counts
+            .entry(date.weekday().num_days_from_monday())
+            .or_insert(0) += 1;
+    }
+    let mut dominant_weekday_and_count: Option<(u32, usize)> = None;
+    for (weekday, count) in &weekday_counts {
+        let is_new_best = match dominant_weekday_and_count {
+            Some((_, best_count)) => *count > best_count,
+            None => true,
+        };
+        if is_new_best {
+            dominant_weekday_and_count = Some((*weekday, *count));
+        }
+    }
+    let dominant_weekday = Weekday::try_from(dominant_weekday_and_count.expect("dates is non-empty").0 as u8)
+        .expect("0..=6 is always a valid weekday index");
+
+    let dtstart = dates
+        .iter()
+        .find(|date| date.weekday() == dominant_weekday)
+        .copied()
+        .expect("dominant weekday came from these dates");
+    let year_end = NaiveDate::from_ymd_opt(year, 12, 31).expect("valid year end");
+
+    let mut rule_dates = Vec::new();
+    let mut cursor = dtstart;
+    while cursor <= year_end {
+        rule_dates.push(cursor);
+        cursor += Duration::weeks(interval);
+    }
+
+    let real: HashSet<_> = dates.iter().copied().collect();
+    let generated: HashSet<_> = rule_dates.iter().copied().collect();
+
+    let exdates: Vec<NaiveDate> = rule_dates
+        .iter()
+        .filter(|date| !real.contains(date))
+        .copied()
+        .collect();
+    let rdates: Vec<NaiveDate> = dates
+        .iter()
+        .filter(|date| !generated.contains(date))
+        .copied()
+        .collect();
+
+    let byday = match dominant_weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    };
+    let rrule = format!(
+        "FREQ=WEEKLY;INTERVAL={};BYDAY={};UNTIL={}",
+        interval,
+        byday,
+        format_ics_date(year_end)
+    );
+
+    let mut event = Event::new();
+    event.all_day(dtstart);
+    event.summary(name);
+    event.description(name);
+    event.append_property(Property::new("RRULE", rrule).done());
+    if !exdates.is_empty() {
+        let value = exdates
+            .iter()
+            .map(|date| format_ics_date(*date))
+            .collect::<Vec<_>>()
+            .join(",");
+        event.append_property(Property::new("EXDATE", value).add_parameter("VALUE", "DATE").done());
+    }
+    if !rdates.is_empty() {
+        let value = rdates
+            .iter()
+            .map(|date| format_ics_date(*date))
+            .collect::<Vec<_>>()
+            .join(",");
+        event.append_property(Property::new("RDATE", value).add_parameter("VALUE", "DATE").done());
+    }
+    for reminder in reminders {
+        event.alarm(Alarm::display(name, -*reminder));
+    }
+
+    vec![event.done()]
+}
+
 fn parse_year(
     csv_iter: &mut std::iter::Enumerate<csv::StringRecordsIter<'_, File>>,
 ) -> Result<i32, Error> {
@@ -175,10 +683,52 @@ fn parse_trash_entries(
     Ok(entries)
 }
 
+/// A dated exception on top of the one-to-one remapping table: either a pickup is
+/// cancelled outright (a holiday with no replacement), or a fresh pickup of some type is
+/// added that doesn't correspond to any shifted date.
+#[derive(Debug, Clone, Copy)]
+enum ConversionException {
+    Removed,
+    Added(TrashType),
+}
+
+fn parse_simple_date(raw: &str) -> Option<SimpleDate> {
+    let parts: Vec<_> = raw.split('/').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+    Some(SimpleDate {
+        month: parts[1].trim().parse().ok()?,
+        day: parts[0].trim().parse().ok()?,
+    })
+}
+
+fn is_removed_marker(raw: &str) -> bool {
+    matches!(
+        raw.trim(),
+        "-" | "odwołane" | "odwolane" | "anulowane" | "brak"
+    )
+}
+
+fn trash_type_by_name(names: &HashMap<TrashType, String>, raw: &str) -> Option<TrashType> {
+    let raw = raw.trim();
+    names
+        .iter()
+        .find(|(_, name)| name.as_str() == raw)
+        .map(|(ty, _)| *ty)
+}
+
+struct ConversionTables {
+    remaps: HashMap<SimpleDate, SimpleDate>,
+    exceptions: HashMap<SimpleDate, ConversionException>,
+}
+
 fn parse_conversions(
     csv_iter: &mut std::iter::Enumerate<csv::StringRecordsIter<'_, File>>,
-) -> Result<HashMap<SimpleDate, SimpleDate>, Error> {
+    names: &HashMap<TrashType, String>,
+) -> Result<ConversionTables, Error> {
     let mut conversions: HashMap<SimpleDate, SimpleDate> = HashMap::new();
+    let mut exceptions: HashMap<SimpleDate, ConversionException> = HashMap::new();
     for (_, record) in csv_iter.by_ref() {
         let record = record?;
         let mut entries_iter = record.into_iter();
@@ -197,29 +747,27 @@ fn parse_conversions(
             println!("Bad replacement formatting, second date missing");
             break;
         }
-        let from: Vec<_> = record[0]
-            .to_owned()
-            .split('/')
-            .map(|s| s.to_owned())
-            .collect();
-        let to: Vec<_> = record[1]
-            .to_owned()
-            .split('/')
-            .map(|s| s.to_owned())
-            .collect();
-
-        conversions.insert(
-            SimpleDate {
-                month: from[1].parse()?,
-                day: from[0].parse()?,
-            },
-            SimpleDate {
-                month: to[1].parse()?,
-                day: to[0].parse()?,
-            },
-        );
+        let from = parse_simple_date(&record[0]).ok_or_else(|| {
+            anyhow!(format!("Couldn't parse exception date {}", &record[0]))
+        })?;
+        let second = &record[1];
+
+        if let Some(to) = parse_simple_date(second) {
+            conversions.insert(from, to);
+            continue;
+        }
+        if is_removed_marker(second) {
+            exceptions.insert(from, ConversionException::Removed);
+            continue;
+        }
+        let ty = trash_type_by_name(names, second)
+            .ok_or_else(|| anyhow!(format!("Unknown trash type in conversions: {}", second)))?;
+        exceptions.insert(from, ConversionException::Added(ty));
     }
-    Ok(conversions)
+    Ok(ConversionTables {
+        remaps: conversions,
+        exceptions,
+    })
 }
 struct InputTrashEntry {
     month_number: u32,
@@ -251,7 +799,7 @@ fn polish_name_to_weekday(name: String) -> Result<Weekday> {
     }
 }
 
-#[derive(PartialEq, Eq, Hash)]
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
 enum TrashType {
     Mixed,
     Metal,